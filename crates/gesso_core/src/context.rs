@@ -0,0 +1,111 @@
+//! Application/window context for gesso: the glue between the OS-reported
+//! DPI factor, the current physical window resolution, and the effective
+//! [`ScaleFactor`] content gets laid out at.
+
+use crate::geometry::{ScaleFactor, Size};
+
+/// How logical content should be scaled to the physical window.
+///
+/// This is the scaling-strategy model from Veloren's UI: apps can pick
+/// fixed-DPI, a user-chosen absolute scale, or a design-resolution-relative
+/// scale, instead of being locked to whatever the OS reports.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScaleMode {
+    /// Use the OS-reported DPI factor directly.
+    DpiFactor,
+    /// A fixed, user-chosen scale, independent of DPI.
+    Absolute(f32),
+    /// Scale so that `reference` maps onto the current physical window,
+    /// preserving aspect ratio by using the smaller of the width/height
+    /// ratios (so the reference design fits without overflow).
+    RelativeToWindow { reference: Size },
+}
+
+/// Resolves a [`ScaleMode`] against the current physical window resolution
+/// and DPI factor into an effective [`ScaleFactor`].
+///
+/// The context recomputes this whenever the window resizes or the OS
+/// reports a new DPI factor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Scale {
+    mode: ScaleMode,
+    physical_size: Size,
+    dpi_factor: f32,
+}
+
+impl Scale {
+    pub fn new(mode: ScaleMode, physical_size: Size, dpi_factor: f32) -> Self {
+        Self {
+            mode,
+            physical_size,
+            dpi_factor,
+        }
+    }
+
+    pub fn mode(&self) -> ScaleMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: ScaleMode) {
+        self.mode = mode;
+    }
+
+    /// Call when the window resizes so the effective scale factor is
+    /// recomputed against the new physical resolution.
+    pub fn set_physical_size(&mut self, physical_size: Size) {
+        self.physical_size = physical_size;
+    }
+
+    /// Call when the OS reports a new DPI factor (e.g. the window moved to
+    /// a different monitor).
+    pub fn set_dpi_factor(&mut self, dpi_factor: f32) {
+        self.dpi_factor = dpi_factor;
+    }
+
+    /// Resolves the current mode into an effective [`ScaleFactor`].
+    pub fn resolve(&self) -> ScaleFactor {
+        match self.mode {
+            ScaleMode::DpiFactor => ScaleFactor(self.dpi_factor),
+            ScaleMode::Absolute(factor) => ScaleFactor(factor),
+            ScaleMode::RelativeToWindow { reference } => {
+                let width_ratio = self.physical_size.width / reference.width;
+                let height_ratio = self.physical_size.height / reference.height;
+                ScaleFactor(width_ratio.min(height_ratio))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dpi_factor_mode_uses_os_factor() {
+        let scale = Scale::new(ScaleMode::DpiFactor, Size::new(1920.0, 1080.0), 2.0);
+        assert_eq!(scale.resolve().0, 2.0);
+    }
+
+    #[test]
+    fn absolute_mode_ignores_dpi_and_size() {
+        let scale = Scale::new(ScaleMode::Absolute(1.5), Size::new(1920.0, 1080.0), 2.0);
+        assert_eq!(scale.resolve().0, 1.5);
+    }
+
+    #[test]
+    fn relative_to_window_uses_smaller_ratio() {
+        let reference = Size::new(800.0, 600.0);
+        let mut scale = Scale::new(
+            ScaleMode::RelativeToWindow { reference },
+            Size::new(1600.0, 900.0),
+            1.0,
+        );
+        // Width ratio is 2.0, height ratio is 1.5 - the smaller wins so the
+        // reference design still fits entirely within the window.
+        assert_eq!(scale.resolve().0, 1.5);
+
+        scale.set_physical_size(Size::new(400.0, 1200.0));
+        // Width ratio is 0.5, height ratio is 2.0.
+        assert_eq!(scale.resolve().0, 0.5);
+    }
+}
@@ -1,5 +1,7 @@
 //! Core geometry primitives for gesso.
 
+use std::marker::PhantomData;
+
 /// Logical pixels - DPI-independent coordinate space.
 pub struct LogicalPixels;
 
@@ -25,6 +27,76 @@ pub type DevicePoint = glamour::Point2<DevicePixels>;
 pub type DeviceSize = glamour::Size2<DevicePixels>;
 pub type DeviceRect = glamour::Rect<DevicePixels>;
 
+/// World pixels - scene content space, before the viewport transform is
+/// applied. Scroll offsets, layout, and hit-testing all happen here.
+pub struct WorldPixels;
+
+impl glamour::Unit for WorldPixels {
+    type Scalar = f32;
+}
+
+/// Window pixels - the final render target/framebuffer space, after the
+/// viewport transform but before any device-pixel rounding.
+///
+/// Keeping `WorldPixels` and `WindowPixels` distinct from `DevicePixels` at
+/// the type level follows WebRender's units model: it prevents accidentally
+/// mixing scroll-offset coordinates with device coordinates, a common source
+/// of rendering bugs.
+pub struct WindowPixels;
+
+impl glamour::Unit for WindowPixels {
+    type Scalar = f32;
+}
+
+// World space type aliases
+pub type WorldPoint = glamour::Point2<WorldPixels>;
+pub type WorldSize = glamour::Size2<WorldPixels>;
+pub type WorldRect = glamour::Rect<WorldPixels>;
+pub type WorldVector = glamour::Vector2<WorldPixels>;
+
+// Window space type aliases
+pub type WindowPoint = glamour::Point2<WindowPixels>;
+pub type WindowSize = glamour::Size2<WindowPixels>;
+pub type WindowRect = glamour::Rect<WindowPixels>;
+pub type WindowVector = glamour::Vector2<WindowPixels>;
+
+/// Transform from scene content space to the window/framebuffer space,
+/// e.g. applying scroll offset and viewport placement.
+pub type WorldToWindowTransform = Transform2D<WorldPixels, WindowPixels>;
+
+/// Transform from the window/framebuffer space to physical device pixels,
+/// e.g. applying the DPI scale factor.
+pub type WindowToDeviceTransform = Transform2D<WindowPixels, DevicePixels>;
+
+/// Integer device pixels - whole physical pixels on the render target.
+///
+/// Following winit's physical/logical distinction and WebRender's
+/// `DeviceIntRect`, snapping to this space before rasterizing avoids the
+/// blurry edges that fractional `DevicePixels` coordinates cause.
+pub struct DeviceIntPixels;
+
+impl glamour::Unit for DeviceIntPixels {
+    type Scalar = i32;
+}
+
+pub type DeviceIntPoint = glamour::Point2<DeviceIntPixels>;
+pub type DeviceIntSize = glamour::Size2<DeviceIntPixels>;
+pub type DeviceIntRect = glamour::Rect<DeviceIntPixels>;
+
+/// How to snap fractional device-pixel geometry to whole pixels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingPolicy {
+    /// Round each coordinate to the nearest whole pixel.
+    Round,
+    /// Round each coordinate down.
+    Floor,
+    /// Round each coordinate up.
+    Ceil,
+    /// Floor the origin and ceil the far edge, so the rounded rect never
+    /// shrinks below the logical coverage it was derived from.
+    Expand,
+}
+
 /// Scale factor for converting between logical and device pixels.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ScaleFactor(pub f32);
@@ -53,6 +125,487 @@ impl ScaleFactor {
     pub fn unscale_rect(&self, r: DeviceRect) -> Rect {
         Rect::new(self.unscale_point(r.origin), self.unscale_size(r.size))
     }
+
+    /// Builds the equivalent uniform-scale [`Transform2D`] for this factor,
+    /// so callers that push a transform stack don't need a separate code
+    /// path for plain DPI scaling.
+    pub fn to_transform(&self) -> Transform2D<LogicalPixels, DevicePixels> {
+        Transform2D::scale(self.0, self.0)
+    }
+
+    /// Scales a point to device pixels and snaps it to a whole pixel,
+    /// so the renderer can place content on exact framebuffer pixels.
+    pub fn scale_point_rounded(&self, p: Point, policy: RoundingPolicy) -> DeviceIntPoint {
+        let scaled = self.scale_point(p);
+        DeviceIntPoint::new(
+            round_coord(scaled.x, policy),
+            round_coord(scaled.y, policy),
+        )
+    }
+
+    /// Scales a size to device pixels and snaps it to whole pixels. `Expand`
+    /// rounds up so the result never shrinks below the logical coverage.
+    pub fn scale_size_rounded(&self, s: Size, policy: RoundingPolicy) -> DeviceIntSize {
+        let scaled = self.scale_size(s);
+        let policy = if policy == RoundingPolicy::Expand {
+            RoundingPolicy::Ceil
+        } else {
+            policy
+        };
+        DeviceIntSize::new(
+            round_coord(scaled.width, policy),
+            round_coord(scaled.height, policy),
+        )
+    }
+
+    /// Scales a rect to device pixels and snaps it to whole pixels.
+    ///
+    /// `Expand` floors the origin and ceils the far edge independently, so
+    /// the rounded rect always fully covers its logical source rect rather
+    /// than just rounding width/height, which could clip a corner.
+    pub fn scale_rect_rounded(&self, r: Rect, policy: RoundingPolicy) -> DeviceIntRect {
+        let scaled = self.scale_rect(r);
+        let (origin_policy, far_policy) = match policy {
+            RoundingPolicy::Expand => (RoundingPolicy::Floor, RoundingPolicy::Ceil),
+            other => (other, other),
+        };
+
+        let min_x = round_coord(scaled.origin.x, origin_policy);
+        let min_y = round_coord(scaled.origin.y, origin_policy);
+        let max_x = round_coord(scaled.origin.x + scaled.size.width, far_policy);
+        let max_y = round_coord(scaled.origin.y + scaled.size.height, far_policy);
+
+        DeviceIntRect::new(
+            DeviceIntPoint::new(min_x, min_y),
+            DeviceIntSize::new(max_x - min_x, max_y - min_y),
+        )
+    }
+}
+
+/// Number of [`Au`] per logical pixel. 60 is divisible by 2, 3, 4, 5, and 6,
+/// so common fractional layouts (thirds, quarters, fifths) convert to a
+/// whole number of `Au` instead of drifting under repeated float rounding.
+pub const AU_PER_PX: i32 = 60;
+
+/// A sub-pixel fixed-point length, in units of 1/60 of a logical pixel.
+///
+/// Floating-point accumulation in layout produces drift and
+/// non-reproducible rounding; `Au` keeps layout arithmetic exact and
+/// comparable, the approach Servo/WebRender use via `app_units::Au`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Au(pub i32);
+
+impl Au {
+    pub const ZERO: Au = Au(0);
+
+    pub fn from_px(px: f32) -> Self {
+        Self::from_f32_round(px * AU_PER_PX as f32)
+    }
+
+    pub fn to_px(self) -> f32 {
+        self.0 as f32 / AU_PER_PX as f32
+    }
+
+    /// Rounds a value already expressed in `Au` units to the nearest whole `Au`.
+    pub fn from_f32_round(value: f32) -> Self {
+        Au(value.round() as i32)
+    }
+
+    pub fn saturating_add(self, other: Au) -> Au {
+        Au(self.0.saturating_add(other.0))
+    }
+
+    pub fn saturating_sub(self, other: Au) -> Au {
+        Au(self.0.saturating_sub(other.0))
+    }
+
+    pub fn saturating_mul(self, factor: i32) -> Au {
+        Au(self.0.saturating_mul(factor))
+    }
+}
+
+/// A point in [`Au`] units.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AuPoint {
+    pub x: Au,
+    pub y: Au,
+}
+
+impl AuPoint {
+    pub fn new(x: Au, y: Au) -> Self {
+        Self { x, y }
+    }
+
+    pub fn from_px(point: Point) -> Self {
+        Self::new(Au::from_px(point.x), Au::from_px(point.y))
+    }
+
+    pub fn to_px(self) -> Point {
+        Point::new(self.x.to_px(), self.y.to_px())
+    }
+}
+
+/// A size in [`Au`] units.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AuSize {
+    pub width: Au,
+    pub height: Au,
+}
+
+impl AuSize {
+    pub fn new(width: Au, height: Au) -> Self {
+        Self { width, height }
+    }
+
+    pub fn from_px(size: Size) -> Self {
+        Self::new(Au::from_px(size.width), Au::from_px(size.height))
+    }
+
+    pub fn to_px(self) -> Size {
+        Size::new(self.width.to_px(), self.height.to_px())
+    }
+}
+
+/// A rect in [`Au`] units.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AuRect {
+    pub origin: AuPoint,
+    pub size: AuSize,
+}
+
+impl AuRect {
+    pub fn new(origin: AuPoint, size: AuSize) -> Self {
+        Self { origin, size }
+    }
+
+    pub fn from_px(rect: Rect) -> Self {
+        Self::new(AuPoint::from_px(rect.origin), AuSize::from_px(rect.size))
+    }
+
+    pub fn to_px(self) -> Rect {
+        Rect::new(self.origin.to_px(), self.size.to_px())
+    }
+}
+
+/// Edge offsets (top/right/bottom/left) in logical pixels, for insetting or
+/// outsetting a rect - padding, borders, and hit-test margins. Brings
+/// WebRender's `SideOffsets2D` into the crate.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SideOffsets {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl SideOffsets {
+    pub fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// The same offset on all four sides.
+    pub fn uniform(v: f32) -> Self {
+        Self::new(v, v, v, v)
+    }
+
+    /// `horizontal` on left/right, `vertical` on top/bottom.
+    pub fn symmetric(horizontal: f32, vertical: f32) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+}
+
+/// Inset/outset extension for rects.
+pub trait RectExt {
+    /// Shrinks the rect by moving the origin in by `(left, top)` and
+    /// reducing the size by `(left + right, top + bottom)`.
+    fn inset(&self, offsets: SideOffsets) -> Self;
+
+    /// Grows the rect by moving the origin out by `(left, top)` and
+    /// increasing the size by `(left + right, top + bottom)`.
+    fn outset(&self, offsets: SideOffsets) -> Self;
+
+    /// Whether this rect's size is empty.
+    fn is_empty(&self) -> bool;
+}
+
+impl RectExt for Rect {
+    fn inset(&self, offsets: SideOffsets) -> Self {
+        Rect::new(
+            Point::new(self.origin.x + offsets.left, self.origin.y + offsets.top),
+            Size::new(
+                self.size.width - offsets.left - offsets.right,
+                self.size.height - offsets.top - offsets.bottom,
+            ),
+        )
+    }
+
+    fn outset(&self, offsets: SideOffsets) -> Self {
+        Rect::new(
+            Point::new(self.origin.x - offsets.left, self.origin.y - offsets.top),
+            Size::new(
+                self.size.width + offsets.left + offsets.right,
+                self.size.height + offsets.top + offsets.bottom,
+            ),
+        )
+    }
+
+    fn is_empty(&self) -> bool {
+        self.size.is_empty()
+    }
+}
+
+/// Compares floating-point geometry for approximate equality, using a
+/// relative-plus-absolute tolerance so comparisons stay robust for both
+/// small and large magnitudes. Needed because exact `assert_eq!` on `f32`
+/// breaks for non-power-of-two scale factors; this is the approach jiao's
+/// `Size` uses via `fuzzy_compare`.
+pub trait FuzzyEq {
+    fn fuzzy_eq(&self, other: &Self, epsilon: f32) -> bool;
+}
+
+fn fuzzy_eq_f32(a: f32, b: f32, epsilon: f32) -> bool {
+    (a - b).abs() <= epsilon * 1.0_f32.max(a.abs()).max(b.abs())
+}
+
+impl FuzzyEq for Point {
+    fn fuzzy_eq(&self, other: &Self, epsilon: f32) -> bool {
+        fuzzy_eq_f32(self.x, other.x, epsilon) && fuzzy_eq_f32(self.y, other.y, epsilon)
+    }
+}
+
+impl FuzzyEq for Size {
+    fn fuzzy_eq(&self, other: &Self, epsilon: f32) -> bool {
+        fuzzy_eq_f32(self.width, other.width, epsilon)
+            && fuzzy_eq_f32(self.height, other.height, epsilon)
+    }
+}
+
+impl FuzzyEq for Rect {
+    fn fuzzy_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.origin.fuzzy_eq(&other.origin, epsilon) && self.size.fuzzy_eq(&other.size, epsilon)
+    }
+}
+
+/// Aspect-ratio aware layout helpers, useful for fitting images/canvases
+/// into viewports (the aspect-ratio math from crosvm's `SizeExtension`).
+pub trait SizeExt {
+    fn aspect_ratio(&self) -> f32;
+
+    /// Whether width and height are equal.
+    fn is_square(&self) -> bool;
+
+    /// Whether this size is wider than it is tall.
+    fn is_landscape(&self) -> bool;
+
+    /// The largest size with this aspect ratio that fits entirely within
+    /// `container` (letterbox/"contain").
+    fn fit_inside(&self, container: Self) -> Self;
+
+    /// The smallest size with this aspect ratio that fully covers
+    /// `container`, overflowing on one axis ("cover").
+    fn fill(&self, container: Self) -> Self;
+
+    /// Returns a rect of size `self`, centered within `container`.
+    fn center_in(&self, container: Rect) -> Rect;
+
+    /// Whether width or height is `<= 0`.
+    fn is_empty(&self) -> bool;
+
+    /// Whether both width and height are `> 0`.
+    fn is_valid(&self) -> bool;
+}
+
+impl SizeExt for Size {
+    fn aspect_ratio(&self) -> f32 {
+        self.width / self.height
+    }
+
+    fn is_square(&self) -> bool {
+        (self.width - self.height).abs() < f32::EPSILON
+    }
+
+    fn is_landscape(&self) -> bool {
+        self.width > self.height
+    }
+
+    fn fit_inside(&self, container: Self) -> Self {
+        let scale = if self.aspect_ratio() > container.aspect_ratio() {
+            container.width / self.width
+        } else {
+            container.height / self.height
+        };
+        Self::new((self.width * scale).round(), (self.height * scale).round())
+    }
+
+    fn fill(&self, container: Self) -> Self {
+        let scale = if self.aspect_ratio() > container.aspect_ratio() {
+            container.height / self.height
+        } else {
+            container.width / self.width
+        };
+        Self::new((self.width * scale).round(), (self.height * scale).round())
+    }
+
+    fn center_in(&self, container: Rect) -> Rect {
+        let x = container.origin.x + (container.size.width - self.width) / 2.0;
+        let y = container.origin.y + (container.size.height - self.height) / 2.0;
+        Rect::new(Point::new(x, y), *self)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.width <= 0.0 || self.height <= 0.0
+    }
+
+    fn is_valid(&self) -> bool {
+        self.width > 0.0 && self.height > 0.0
+    }
+}
+
+fn round_coord(v: f32, policy: RoundingPolicy) -> i32 {
+    match policy {
+        RoundingPolicy::Round => v.round() as i32,
+        RoundingPolicy::Floor | RoundingPolicy::Expand => v.floor() as i32,
+        RoundingPolicy::Ceil => v.ceil() as i32,
+    }
+}
+
+/// An affine transform from `Src` space to `Dst` space.
+///
+/// Internally this is the six coefficients of a 3x3 homogeneous matrix (the
+/// third column is implicitly `[0, 0, 1]`), so translation, scale, rotation,
+/// and skew all compose through ordinary matrix multiplication instead of
+/// bare per-axis `f32` math. This mirrors WebRender's
+/// `Transform3D`/`Translation2D`/`Scale` approach of making coordinate-space
+/// conversions type-checked at compile time: a `Transform2D<A, B>` can only
+/// ever be applied to geometry in space `A`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform2D<Src, Dst> {
+    pub m11: f32,
+    pub m12: f32,
+    pub m21: f32,
+    pub m22: f32,
+    pub m31: f32,
+    pub m32: f32,
+    _unit: PhantomData<(Src, Dst)>,
+}
+
+impl<Src, Dst> Transform2D<Src, Dst> {
+    /// Builds a transform directly from the rows of its 3x3 matrix
+    /// (the implicit third column `[0, 0, 1]` is omitted).
+    pub fn from_rows(m11: f32, m12: f32, m21: f32, m22: f32, m31: f32, m32: f32) -> Self {
+        Self {
+            m11,
+            m12,
+            m21,
+            m22,
+            m31,
+            m32,
+            _unit: PhantomData,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self::from_rows(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+
+    pub fn translate(x: f32, y: f32) -> Self {
+        Self::from_rows(1.0, 0.0, 0.0, 1.0, x, y)
+    }
+
+    pub fn scale(x: f32, y: f32) -> Self {
+        Self::from_rows(x, 0.0, 0.0, y, 0.0, 0.0)
+    }
+
+    /// Builds a rotation transform, `radians` counter-clockwise.
+    pub fn rotate(radians: f32) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::from_rows(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    /// Composes `self` followed by `other`, producing a transform directly
+    /// from `Src` to `NewDst`.
+    pub fn then<NewDst>(&self, other: &Transform2D<Dst, NewDst>) -> Transform2D<Src, NewDst> {
+        Transform2D::from_rows(
+            self.m11 * other.m11 + self.m12 * other.m21,
+            self.m11 * other.m12 + self.m12 * other.m22,
+            self.m21 * other.m11 + self.m22 * other.m21,
+            self.m21 * other.m12 + self.m22 * other.m22,
+            self.m31 * other.m11 + self.m32 * other.m21 + other.m31,
+            self.m31 * other.m12 + self.m32 * other.m22 + other.m32,
+        )
+    }
+
+    /// Returns the inverse transform, or `None` if this transform is
+    /// singular (its determinant is ~0, e.g. a zero scale).
+    pub fn inverse(&self) -> Option<Transform2D<Dst, Src>> {
+        let det = self.m11 * self.m22 - self.m12 * self.m21;
+        if det.abs() < f32::EPSILON {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+        let m11 = self.m22 * inv_det;
+        let m12 = -self.m12 * inv_det;
+        let m21 = -self.m21 * inv_det;
+        let m22 = self.m11 * inv_det;
+        let m31 = -(self.m31 * m11 + self.m32 * m21);
+        let m32 = -(self.m31 * m12 + self.m32 * m22);
+        Some(Transform2D::from_rows(m11, m12, m21, m22, m31, m32))
+    }
+}
+
+impl<Src, Dst> Transform2D<Src, Dst>
+where
+    Src: glamour::Unit<Scalar = f32>,
+    Dst: glamour::Unit<Scalar = f32>,
+{
+    pub fn transform_point(&self, p: glamour::Point2<Src>) -> glamour::Point2<Dst> {
+        glamour::Point2::new(
+            p.x * self.m11 + p.y * self.m21 + self.m31,
+            p.x * self.m12 + p.y * self.m22 + self.m32,
+        )
+    }
+
+    /// Transforms a vector, ignoring the translation component.
+    pub fn transform_vector(&self, v: glamour::Vector2<Src>) -> glamour::Vector2<Dst> {
+        glamour::Vector2::new(
+            v.x * self.m11 + v.y * self.m21,
+            v.x * self.m12 + v.y * self.m22,
+        )
+    }
+
+    /// Transforms a rect by mapping all four corners and taking their
+    /// bounding box, since a rotated or skewed rect is no longer
+    /// axis-aligned.
+    pub fn transform_rect(&self, r: glamour::Rect<Src>) -> glamour::Rect<Dst> {
+        let top_left = self.transform_point(r.origin);
+        let top_right = self.transform_point(glamour::Point2::new(
+            r.origin.x + r.size.width,
+            r.origin.y,
+        ));
+        let bottom_left = self.transform_point(glamour::Point2::new(
+            r.origin.x,
+            r.origin.y + r.size.height,
+        ));
+        let bottom_right = self.transform_point(glamour::Point2::new(
+            r.origin.x + r.size.width,
+            r.origin.y + r.size.height,
+        ));
+
+        let min_x = top_left.x.min(top_right.x).min(bottom_left.x).min(bottom_right.x);
+        let min_y = top_left.y.min(top_right.y).min(bottom_left.y).min(bottom_right.y);
+        let max_x = top_left.x.max(top_right.x).max(bottom_left.x).max(bottom_right.x);
+        let max_y = top_left.y.max(top_right.y).max(bottom_left.y).max(bottom_right.y);
+
+        glamour::Rect::new(
+            glamour::Point2::new(min_x, min_y),
+            glamour::Size2::new(max_x - min_x, max_y - min_y),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -80,4 +633,176 @@ mod tests {
         assert_eq!(original.size.width, back.size.width);
         assert_eq!(original.size.height, back.size.height);
     }
+
+    #[test]
+    fn transform_translate_then_scale() {
+        let translate = Transform2D::<LogicalPixels, LogicalPixels>::translate(10.0, 0.0);
+        let scale = Transform2D::<LogicalPixels, DevicePixels>::scale(2.0, 2.0);
+        let combined = translate.then(&scale);
+
+        let point = combined.transform_point(Point::new(5.0, 5.0));
+        assert_eq!(point.x, 30.0);
+        assert_eq!(point.y, 10.0);
+    }
+
+    #[test]
+    fn transform_inverse_roundtrip() {
+        let transform = Transform2D::<LogicalPixels, DevicePixels>::translate(3.0, -4.0)
+            .then(&Transform2D::scale(2.0, 0.5));
+        let inverse = transform.inverse().expect("transform should be invertible");
+
+        let original = Point::new(7.0, 9.0);
+        let forward = transform.transform_point(original);
+        let back = inverse.transform_point(forward);
+        assert!((original.x - back.x).abs() < 1e-4);
+        assert!((original.y - back.y).abs() < 1e-4);
+    }
+
+    #[test]
+    fn transform_singular_has_no_inverse() {
+        let transform = Transform2D::<LogicalPixels, LogicalPixels>::scale(0.0, 1.0);
+        assert!(transform.inverse().is_none());
+    }
+
+    #[test]
+    fn transform_rect_rotation_yields_bounding_box() {
+        let rotate = Transform2D::<LogicalPixels, LogicalPixels>::rotate(std::f32::consts::FRAC_PI_2);
+        let rect = Rect::new(Point::new(0.0, 0.0), Size::new(2.0, 1.0));
+        let rotated = rotate.transform_rect(rect);
+
+        assert!((rotated.size.width - 1.0).abs() < 1e-4);
+        assert!((rotated.size.height - 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn world_to_device_via_window() {
+        let world_to_window: WorldToWindowTransform = Transform2D::translate(-50.0, -20.0);
+        let window_to_device: WindowToDeviceTransform = Transform2D::scale(2.0, 2.0);
+        let world_to_device = world_to_window.then(&window_to_device);
+
+        let scrolled = world_to_device.transform_point(WorldPoint::new(60.0, 25.0));
+        assert_eq!(scrolled.x, 20.0);
+        assert_eq!(scrolled.y, 10.0);
+    }
+
+    #[test]
+    fn scale_rect_rounded_expand_never_shrinks() {
+        let scale = ScaleFactor(1.0);
+        let rect = Rect::new(Point::new(0.3, 0.3), Size::new(1.4, 1.4));
+        let rounded = scale.scale_rect_rounded(rect, RoundingPolicy::Expand);
+
+        assert_eq!(rounded.origin.x, 0);
+        assert_eq!(rounded.origin.y, 0);
+        assert_eq!(rounded.size.width, 2);
+        assert_eq!(rounded.size.height, 2);
+    }
+
+    #[test]
+    fn scale_point_rounded_variants() {
+        let scale = ScaleFactor(1.0);
+        let p = Point::new(1.6, 1.4);
+
+        assert_eq!(scale.scale_point_rounded(p, RoundingPolicy::Round), DeviceIntPoint::new(2, 1));
+        assert_eq!(scale.scale_point_rounded(p, RoundingPolicy::Floor), DeviceIntPoint::new(1, 1));
+        assert_eq!(scale.scale_point_rounded(p, RoundingPolicy::Ceil), DeviceIntPoint::new(2, 2));
+    }
+
+    #[test]
+    fn fit_inside_letterboxes_wide_content() {
+        let content = Size::new(1600.0, 900.0);
+        let container = Size::new(800.0, 800.0);
+        let fitted = content.fit_inside(container);
+
+        assert_eq!(fitted.width, 800.0);
+        assert_eq!(fitted.height, 450.0);
+    }
+
+    #[test]
+    fn fill_covers_container_with_overflow() {
+        let content = Size::new(1600.0, 900.0);
+        let container = Size::new(800.0, 800.0);
+        let filled = content.fill(container);
+
+        assert_eq!(filled.width, 1422.0);
+        assert_eq!(filled.height, 800.0);
+    }
+
+    #[test]
+    fn center_in_positions_fitted_size() {
+        let content = Size::new(1600.0, 900.0);
+        let container_rect = Rect::new(Point::new(0.0, 0.0), Size::new(800.0, 800.0));
+        let fitted = content.fit_inside(container_rect.size);
+        let centered = fitted.center_in(container_rect);
+
+        assert_eq!(centered.origin.x, 0.0);
+        assert_eq!(centered.origin.y, 175.0);
+    }
+
+    #[test]
+    fn au_per_px_divides_evenly_for_common_fractions() {
+        for divisor in [2, 3, 4, 5, 6] {
+            assert_eq!(AU_PER_PX % divisor, 0);
+        }
+    }
+
+    #[test]
+    fn au_roundtrips_with_logical_pixels() {
+        let point = Point::new(10.5, 3.25);
+        let au_point = AuPoint::from_px(point);
+        assert_eq!(au_point.x, Au(630));
+        assert_eq!(au_point.to_px(), point);
+    }
+
+    #[test]
+    fn au_saturating_add_does_not_overflow() {
+        let max = Au(i32::MAX);
+        assert_eq!(max.saturating_add(Au(1)), max);
+    }
+
+    #[test]
+    fn inset_shrinks_by_offsets() {
+        let rect = Rect::new(Point::new(10.0, 10.0), Size::new(100.0, 50.0));
+        let inset = rect.inset(SideOffsets::new(1.0, 2.0, 3.0, 4.0));
+
+        assert_eq!(inset.origin.x, 14.0);
+        assert_eq!(inset.origin.y, 11.0);
+        assert_eq!(inset.size.width, 94.0);
+        assert_eq!(inset.size.height, 46.0);
+    }
+
+    #[test]
+    fn outset_is_the_inverse_of_inset() {
+        let rect = Rect::new(Point::new(10.0, 10.0), Size::new(100.0, 50.0));
+        let offsets = SideOffsets::uniform(5.0);
+        let round_tripped = rect.outset(offsets).inset(offsets);
+
+        assert_eq!(round_tripped.origin.x, rect.origin.x);
+        assert_eq!(round_tripped.origin.y, rect.origin.y);
+        assert_eq!(round_tripped.size.width, rect.size.width);
+        assert_eq!(round_tripped.size.height, rect.size.height);
+    }
+
+    #[test]
+    fn size_validity_predicates() {
+        assert!(Size::new(10.0, 10.0).is_valid());
+        assert!(!Size::new(10.0, 10.0).is_empty());
+        assert!(Size::new(0.0, 10.0).is_empty());
+        assert!(!Size::new(0.0, 10.0).is_valid());
+    }
+
+    #[test]
+    fn rect_is_empty_follows_its_size() {
+        let empty = Rect::new(Point::new(0.0, 0.0), Size::new(10.0, 0.0));
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn fuzzy_eq_tolerates_accumulated_float_error() {
+        let scale = ScaleFactor(1.0 / 3.0);
+        let original = Point::new(7.0, 7.0);
+        let roundtripped = scale.unscale_point(scale.scale_point(original));
+
+        assert_ne!(original.x, roundtripped.x);
+        assert!(original.fuzzy_eq(&roundtripped, 1e-4));
+    }
 }